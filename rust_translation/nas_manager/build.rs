@@ -0,0 +1,291 @@
+//! Compiles Qt Designer `.ui` files under `ui/` into a single generated
+//! module, `$OUT_DIR/ui.rs`, so Designer edits are picked up by the next
+//! build without any hand-written widget construction.
+//!
+//! Each top-level `<widget>` becomes a struct named after its `<class>`,
+//! with a `load()` associated function that rebuilds the widget tree and
+//! a field for every named descendant so calling code can wire behavior
+//! onto them directly.
+
+use roxmltree::{Document, Node};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let ui_dir = Path::new("ui");
+    println!("cargo:rerun-if-changed=ui");
+
+    let mut generated = String::new();
+    if ui_dir.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(ui_dir)
+            .expect("read ui/ directory")
+            .map(|entry| entry.expect("read ui/ entry").path())
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("ui"))
+            .collect();
+        entries.sort();
+
+        for path in entries {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let xml = fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+            generated.push_str(&generate_struct(&xml, &path.display().to_string()));
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR set by cargo");
+    let dest = Path::new(&out_dir).join("ui.rs");
+    fs::write(&dest, generated).expect("write generated ui.rs");
+
+    compile_icon_resources(&out_dir);
+}
+
+/// Compiles `resources/icons.qrc` with Qt's `rcc` and links the result
+/// in, so the fallback icons used by [`crate::icons`] are baked into the
+/// binary instead of needing to live next to it at runtime.
+fn compile_icon_resources(out_dir: &str) {
+    let qrc_path = Path::new("resources/icons.qrc");
+    println!("cargo:rerun-if-changed={}", qrc_path.display());
+    if !qrc_path.is_file() {
+        return;
+    }
+
+    let cpp_path = Path::new(out_dir).join("icons_qrc.cpp");
+    let status = std::process::Command::new("rcc")
+        .args(["--name", "icons", "--output"])
+        .arg(&cpp_path)
+        .arg(qrc_path)
+        .status()
+        .expect("run rcc (Qt's resource compiler) on resources/icons.qrc");
+    assert!(status.success(), "rcc failed to compile resources/icons.qrc");
+
+    // rcc registers the resource via a global constructor in the
+    // generated translation unit, but that only runs if the object file
+    // actually ends up in the binary — a static archive drops object
+    // files nothing references. This shim gives Rust a symbol to call,
+    // which forces the linker to pull in both objects.
+    let init_path = Path::new(out_dir).join("icons_qrc_init.cpp");
+    fs::write(
+        &init_path,
+        "#include <QtCore/QtCore>\n\
+         extern \"C\" void contara_init_icons_resource() { Q_INIT_RESOURCE(icons); }\n",
+    )
+    .expect("write icons_qrc_init.cpp");
+
+    let qt_headers = qmake_query("QT_INSTALL_HEADERS");
+    let qt_libs = qmake_query("QT_INSTALL_LIBS");
+    let qt_major = qmake_query("QT_VERSION")
+        .split('.')
+        .next()
+        .expect("QT_VERSION has a major component")
+        .to_string();
+
+    cc::Build::new()
+        .cpp(true)
+        .include(&qt_headers)
+        .include(Path::new(&qt_headers).join("QtCore"))
+        .file(&cpp_path)
+        .file(&init_path)
+        .compile("icons_qrc");
+
+    println!("cargo:rustc-link-search=native={qt_libs}");
+    println!("cargo:rustc-link-lib=Qt{qt_major}Core");
+}
+
+/// Runs `qmake -query <var>` and returns its trimmed output, e.g. to
+/// locate Qt's headers/libs for compiling generated C++ against.
+fn qmake_query(var: &str) -> String {
+    let output = std::process::Command::new("qmake")
+        .args(["-query", var])
+        .output()
+        .unwrap_or_else(|err| panic!("run qmake -query {var}: {err}"));
+    assert!(output.status.success(), "qmake -query {var} failed");
+    String::from_utf8(output.stdout)
+        .expect("qmake -query output is valid UTF-8")
+        .trim()
+        .to_string()
+}
+
+/// Turns the contents of a single `.ui` file into the Rust source for its
+/// generated struct.
+fn generate_struct(xml: &str, source: &str) -> String {
+    let doc = Document::parse(xml).unwrap_or_else(|err| panic!("invalid XML in {source}: {err}"));
+
+    let class_name = doc
+        .descendants()
+        .find(|n| n.has_tag_name("class"))
+        .and_then(|n| n.text())
+        .unwrap_or_else(|| panic!("{source} has no <class> element"))
+        .trim();
+
+    let root_widget = doc
+        .descendants()
+        .find(|n| n.has_tag_name("widget"))
+        .unwrap_or_else(|| panic!("{source} has no top-level <widget>"));
+
+    let mut fields = Vec::new();
+    collect_named_children(&root_widget, &mut fields, true);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "pub struct {class_name} {{");
+    for (field, class) in &fields {
+        if field == "widget" {
+            let _ = writeln!(out, "    pub widget: qt_widgets::QBox<qt_widgets::{class}>,");
+        } else {
+            let _ = writeln!(out, "    pub {field}: qt_widgets::qt_core::QPtr<qt_widgets::{class}>,");
+        }
+    }
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out, "impl {class_name} {{");
+    let _ = writeln!(out, "    /// Builds the widget tree for this `.ui` file.");
+    let _ = writeln!(out, "    ///");
+    let _ = writeln!(out, "    /// # Safety");
+    let _ = writeln!(out, "    /// Must be called on the Qt GUI thread, after `QApplication` has");
+    let _ = writeln!(out, "    /// been initialized, like any other widget construction.");
+    let _ = writeln!(out, "    pub unsafe fn load() -> Self {{");
+    emit_widget(&root_widget, "widget", &mut out);
+    let _ = write!(out, "        Self {{");
+    for (field, _) in &fields {
+        if field == "widget" {
+            let _ = write!(out, " {field},");
+        } else {
+            let _ = write!(out, " {field}: {field}.into_q_ptr(),");
+        }
+    }
+    let _ = writeln!(out, " }}");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+/// Walks the widget/layout tree, recording the Rust field name and Qt
+/// class for every named widget so the struct body and constructor can be
+/// emitted in one pass.
+fn collect_named_children(widget: &Node, fields: &mut Vec<(String, String)>, is_root: bool) {
+    let class = widget.attribute("class").unwrap_or("QWidget").to_string();
+    if is_root {
+        fields.push(("widget".to_string(), class));
+    } else if let Some(name) = widget.attribute("name") {
+        fields.push((to_snake_case(name), class));
+    }
+
+    for child in widget.children().filter(|n| n.has_tag_name("layout")) {
+        for item in child.children().filter(|n| n.has_tag_name("item")) {
+            if let Some(child_widget) = item.children().find(|n| n.has_tag_name("widget")) {
+                collect_named_children(&child_widget, fields, false);
+            }
+        }
+    }
+    for child in widget.children().filter(|n| n.has_tag_name("widget")) {
+        collect_named_children(&child, fields, false);
+    }
+}
+
+/// Emits the statements that construct `widget` and, recursively, any
+/// layout and children it declares. Children inside a layout are not just
+/// reparented onto `widget` — they're added to the constructed layout,
+/// which is what actually positions and sizes them; without that they'd
+/// all sit at their default geometry, stacked on top of each other.
+/// Children declared directly on `widget`, outside any layout, are
+/// reparented onto it and keep their own `geometry`.
+fn emit_widget(widget: &Node, field: &str, out: &mut String) {
+    let class = widget.attribute("class").unwrap_or("QWidget");
+    match class {
+        "QPushButton" | "QDialog" | "QWidget" | "QListView" | "QLineEdit" => {}
+        other => panic!("unsupported widget class `{other}` (add a mapping in build.rs)"),
+    }
+
+    let _ = writeln!(out, "        let {field} = qt_widgets::{class}::new_0a();");
+
+    for property in widget.children().filter(|n| n.has_tag_name("property")) {
+        emit_property(&property, field, out);
+    }
+
+    if let Some(layout) = widget.children().find(|n| n.has_tag_name("layout")) {
+        emit_layout(&layout, field, out);
+    }
+
+    for child in widget.children().filter(|n| n.has_tag_name("widget")) {
+        let child_field = to_snake_case(child.attribute("name").expect("child widgets must be named"));
+        emit_widget(&child, &child_field, out);
+        let _ = writeln!(out, "        {child_field}.set_parent_1a(&{field});");
+    }
+}
+
+/// Constructs the layout declared for `parent_field` and adds each child
+/// widget to it (which also reparents the child onto `parent_field`).
+fn emit_layout(layout: &Node, parent_field: &str, out: &mut String) {
+    let layout_class = layout.attribute("class").unwrap_or("QVBoxLayout");
+    let layout_field = format!("{parent_field}_layout");
+    let _ = writeln!(
+        out,
+        "        let {layout_field} = qt_widgets::{layout_class}::new_1a(&{parent_field});"
+    );
+
+    for item in layout.children().filter(|n| n.has_tag_name("item")) {
+        if let Some(child_widget) = item.children().find(|n| n.has_tag_name("widget")) {
+            let child_field = to_snake_case(
+                child_widget
+                    .attribute("name")
+                    .expect("child widgets must be named"),
+            );
+            emit_widget(&child_widget, &child_field, out);
+            let _ = writeln!(out, "        {layout_field}.add_widget(&{child_field});");
+        }
+    }
+}
+
+/// Converts a Designer object name like `refreshButton` into the
+/// `refresh_button` identifier used for the generated struct field.
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.extend(ch.to_lowercase());
+    }
+    out
+}
+
+/// Translates a single Designer `<property>` into the matching setter
+/// call on the widget being constructed.
+fn emit_property(property: &Node, field: &str, out: &mut String) {
+    match property.attribute("name") {
+        Some("geometry") => {
+            let rect = property
+                .children()
+                .find(|n| n.has_tag_name("rect"))
+                .expect("geometry property must contain a <rect>");
+            let dim = |tag: &str| -> i32 {
+                rect.children()
+                    .find(|n| n.has_tag_name(tag))
+                    .and_then(|n| n.text())
+                    .and_then(|t| t.trim().parse().ok())
+                    .unwrap_or(0)
+            };
+            let (x, y, w, h) = (dim("x"), dim("y"), dim("width"), dim("height"));
+            let _ = writeln!(out, "        {field}.set_geometry_4a({x}, {y}, {w}, {h});");
+        }
+        Some("windowTitle") => emit_string_setter(property, field, "set_window_title", out),
+        Some("text") => emit_string_setter(property, field, "set_text", out),
+        Some("placeholderText") => emit_string_setter(property, field, "set_placeholder_text", out),
+        _ => {}
+    }
+}
+
+/// Emits `{field}.{setter}(&QString::from_std_str(...))` for a
+/// `<property>` whose value is a plain `<string>` (`windowTitle`,
+/// `text`, ...).
+fn emit_string_setter(property: &Node, field: &str, setter: &str, out: &mut String) {
+    let text = property
+        .children()
+        .find(|n| n.has_tag_name("string"))
+        .and_then(|n| n.text())
+        .unwrap_or_default();
+    let _ = writeln!(
+        out,
+        "        {field}.{setter}(&qt_widgets::qt_core::QString::from_std_str({text:?}));"
+    );
+}
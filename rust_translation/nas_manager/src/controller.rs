@@ -0,0 +1,121 @@
+//! Owns the generated [`ui::MainWindow`](crate::ui::MainWindow) and wires
+//! its buttons up to NAS actions.
+//!
+//! `NasController` is built in two steps: [`NasController::new`]
+//! constructs the widget tree and the `Rc`, then [`NasController::init`]
+//! connects the signals. Splitting construction from wiring sidesteps the
+//! usual borrow problem of a slot closure needing `&self` before `self`
+//! is fully built.
+
+use crate::icons;
+use crate::model::{Volume, VolumeModel};
+use crate::pairing::{self, ConnectionSettings};
+use crate::ui::MainWindow;
+use qt_widgets::qt_core::slots::{SlotNoArgs, SlotOfQString};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct NasController {
+    ui: MainWindow,
+    volumes: VolumeModel,
+    remote: RefCell<Option<ConnectionSettings>>,
+}
+
+impl NasController {
+    /// Builds the widget tree. Call [`NasController::init`] afterwards to
+    /// connect signals.
+    ///
+    /// # Safety
+    /// Must be called on the Qt GUI thread, after `QApplication` has been
+    /// initialized.
+    pub unsafe fn new() -> Rc<Self> {
+        Rc::new(Self {
+            ui: MainWindow::load(),
+            volumes: VolumeModel::new(),
+            remote: RefCell::new(None),
+        })
+    }
+
+    /// Connects each button's `clicked` signal to its slot. Requires an
+    /// `Rc<Self>` so the slot closures can hold a strong reference back
+    /// to the controller.
+    ///
+    /// # Safety
+    /// Must be called on the Qt GUI thread, after `QApplication` has been
+    /// initialized.
+    pub unsafe fn init(self: &Rc<Self>) {
+        let parent = &self.ui.widget;
+        self.ui.volumes_view.set_model(&self.volumes.proxy);
+
+        self.ui.refresh_button.set_icon(&icons::icon("view-refresh"));
+        self.ui.mount_button.set_icon(&icons::icon("drive-harddisk"));
+        self.ui.unmount_button.set_icon(&icons::icon("media-eject"));
+        self.ui.pair_button.set_icon(&icons::icon("view-barcode-qr"));
+
+        let this = self.clone();
+        let on_refresh = SlotNoArgs::new(parent, move || this.on_refresh_clicked());
+        self.ui.refresh_button.clicked().connect(&on_refresh);
+        on_refresh.into_raw_ptr();
+
+        let this = self.clone();
+        let on_mount = SlotNoArgs::new(parent, move || this.on_mount_clicked());
+        self.ui.mount_button.clicked().connect(&on_mount);
+        on_mount.into_raw_ptr();
+
+        let this = self.clone();
+        let on_unmount = SlotNoArgs::new(parent, move || this.on_unmount_clicked());
+        self.ui.unmount_button.clicked().connect(&on_unmount);
+        on_unmount.into_raw_ptr();
+
+        let this = self.clone();
+        let on_pair = SlotNoArgs::new(parent, move || this.on_pair_clicked());
+        self.ui.pair_button.clicked().connect(&on_pair);
+        on_pair.into_raw_ptr();
+
+        let this = self.clone();
+        let on_filter_changed = SlotOfQString::new(parent, move |text| unsafe {
+            this.volumes.set_name_filter(&text.to_std_string());
+        });
+        self.ui.volume_filter_edit.text_changed().connect(&on_filter_changed);
+        on_filter_changed.into_raw_ptr();
+    }
+
+    pub fn show(&self) {
+        unsafe { self.ui.widget.show() };
+    }
+
+    fn on_refresh_clicked(&self) {
+        // Pushes a fresh `scan_volumes` snapshot into the view; that scan
+        // returns no volumes, so this empties the list.
+        unsafe {
+            self.volumes.set_volumes(&self.scan_volumes());
+        }
+    }
+
+    /// Placeholder for the real volume discovery logic.
+    fn scan_volumes(&self) -> Vec<Volume> {
+        Vec::new()
+    }
+
+    fn on_mount_clicked(&self) {
+        // TODO: mount the currently selected share.
+    }
+
+    fn on_unmount_clicked(&self) {
+        // TODO: unmount the currently selected share.
+    }
+
+    fn on_pair_clicked(&self) {
+        unsafe {
+            match pairing::pick_and_decode(&self.ui.widget) {
+                Ok(settings) => {
+                    // TODO: point the NAS connection at this endpoint.
+                    *self.remote.borrow_mut() = Some(settings);
+                }
+                // The user simply closed the file picker; nothing went wrong.
+                Err(pairing::PairingError::Cancelled) => {}
+                Err(err) => pairing::show_pairing_error(&self.ui.widget, &err),
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Cross-platform themed icons for toolbar/action buttons.
+//!
+//! `QIcon::from_theme` only resolves reliably against a freedesktop icon
+//! theme, i.e. on Linux. Elsewhere, or when the active theme simply
+//! doesn't have the requested name, callers fall back to icons bundled
+//! into the binary via the compiled `resources/icons.qrc` resource.
+
+use qt_widgets::qt_core::QString;
+use qt_widgets::qt_gui::QIcon;
+
+extern "C" {
+    /// Defined in the `icons_qrc_init.cpp` shim `build.rs` compiles
+    /// alongside `rcc`'s output; calls `Q_INIT_RESOURCE` so the bundled
+    /// `:/icons/*.svg` resource actually registers at runtime. Calling
+    /// it from Rust is also what keeps the linker from dropping the
+    /// otherwise-unreferenced generated resource object out of the
+    /// static archive.
+    fn contara_init_icons_resource();
+}
+
+/// Registers the bundled icon resource compiled from
+/// `resources/icons.qrc`. Must be called once at startup, before the
+/// first [`icon`]/[`icon_any`] lookup that might need the fallback.
+pub fn register_bundled_resources() {
+    unsafe { contara_init_icons_resource() };
+}
+
+/// Resolves a single themed icon by name, falling back to the bundled
+/// resource of the same name. See [`icon_any`] to supply a
+/// graceful-degradation chain of candidate names.
+pub fn icon(name: &str) -> QIcon {
+    icon_any(&[name])
+}
+
+/// Walks `names` in order and returns the first candidate an icon can
+/// actually be resolved for — first checking the platform icon theme
+/// (Linux only), then the bundled fallback resource — based on whether
+/// the resulting `QIcon`'s `available_sizes` is non-empty. Falls back to
+/// the bundled resource for the first candidate if none resolve
+/// anywhere, so callers always get back *an* icon.
+pub fn icon_any(names: &[&str]) -> QIcon {
+    #[cfg(target_os = "linux")]
+    for name in names {
+        if let Some(found) = from_theme(name) {
+            return found;
+        }
+    }
+
+    for name in names {
+        let bundled = from_resource(name);
+        if has_sizes(&bundled) {
+            return bundled;
+        }
+    }
+
+    names.first().map(|name| from_resource(name)).unwrap_or_else(|| unsafe { QIcon::new_0a() })
+}
+
+#[cfg(target_os = "linux")]
+fn from_theme(name: &str) -> Option<QIcon> {
+    unsafe {
+        if prefers_symbolic() {
+            let symbolic = QIcon::from_theme_1a(&QString::from_std_str(&format!("{name}-symbolic")));
+            if has_sizes(&symbolic) {
+                return Some(symbolic);
+            }
+        }
+
+        let plain = QIcon::from_theme_1a(&QString::from_std_str(name));
+        has_sizes(&plain).then_some(plain)
+    }
+}
+
+/// Symbolic icons are designed to be recolored by the desktop theme, so
+/// they only look right under the desktop styles that actually do that
+/// (GNOME's Adwaita, KDE's Breeze); everywhere else the plain themed
+/// icon is the better match.
+#[cfg(target_os = "linux")]
+fn prefers_symbolic() -> bool {
+    unsafe {
+        let style_name = qt_widgets::QApplication::style().object_name().to_std_string();
+        let style_name = style_name.to_lowercase();
+        style_name.contains("adwaita") || style_name.contains("breeze")
+    }
+}
+
+fn from_resource(name: &str) -> QIcon {
+    unsafe { QIcon::from_q_string(&QString::from_std_str(&format!(":/icons/{name}.svg"))) }
+}
+
+fn has_sizes(icon: &QIcon) -> bool {
+    unsafe { !icon.available_sizes_0a().is_empty() }
+}
@@ -0,0 +1,79 @@
+//! A `QStandardItemModel`-backed list of discovered NAS volumes/shares,
+//! exposed to the view through a `QSortFilterProxyModel` so the user can
+//! filter shares by name without the controller touching widgets
+//! directly.
+
+use qt_widgets::qt_core::{CaseSensitivity, ItemDataRole, QBox, QSortFilterProxyModel, QString, QVariant};
+use qt_widgets::qt_gui::{QStandardItem, QStandardItemModel};
+
+/// One row's worth of data about a discovered volume.
+pub struct Volume {
+    pub mount_name: String,
+    pub filesystem: String,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub smart_health: String,
+}
+
+const ROLE_FILESYSTEM: i32 = 0;
+const ROLE_TOTAL_BYTES: i32 = 1;
+const ROLE_USED_BYTES: i32 = 2;
+const ROLE_SMART_HEALTH: i32 = 3;
+
+fn custom_role(offset: i32) -> i32 {
+    ItemDataRole::UserRole.to_int() + offset
+}
+
+/// Owns the source model and the filter proxy sitting in front of it.
+/// Views should bind to [`VolumeModel::proxy`], never `source` directly,
+/// so filtering keeps working.
+pub struct VolumeModel {
+    source: QBox<QStandardItemModel>,
+    pub proxy: QBox<QSortFilterProxyModel>,
+}
+
+impl VolumeModel {
+    /// # Safety
+    /// Must be called on the Qt GUI thread, after `QApplication` has been
+    /// initialized.
+    pub unsafe fn new() -> Self {
+        let source = QStandardItemModel::new_0a();
+        let proxy = QSortFilterProxyModel::new_0a();
+        proxy.set_source_model(&source);
+        proxy.set_filter_case_sensitivity(CaseSensitivity::CaseInsensitive);
+        Self { source, proxy }
+    }
+
+    /// Restricts the view to volumes whose mount name contains `text`.
+    /// Pass an empty string to clear the filter.
+    pub unsafe fn set_name_filter(&self, text: &str) {
+        self.proxy.set_filter_fixed_string(&QString::from_std_str(text));
+    }
+
+    /// Replaces the model's rows with a fresh snapshot, e.g. after a
+    /// background scan completes. Views bound through `proxy` pick up
+    /// the change via the model's own reset and row-insertion signals.
+    pub unsafe fn set_volumes(&self, volumes: &[Volume]) {
+        self.source.clear();
+        for volume in volumes {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(&volume.mount_name));
+            item.set_data_2a(
+                &QVariant::from_q_string(&QString::from_std_str(&volume.filesystem)),
+                custom_role(ROLE_FILESYSTEM),
+            );
+            item.set_data_2a(
+                &QVariant::from_u64(volume.total_bytes),
+                custom_role(ROLE_TOTAL_BYTES),
+            );
+            item.set_data_2a(
+                &QVariant::from_u64(volume.used_bytes),
+                custom_role(ROLE_USED_BYTES),
+            );
+            item.set_data_2a(
+                &QVariant::from_q_string(&QString::from_std_str(&volume.smart_health)),
+                custom_role(ROLE_SMART_HEALTH),
+            );
+            self.source.append_row_q_standard_item(item.into_ptr());
+        }
+    }
+}
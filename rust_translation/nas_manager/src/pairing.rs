@@ -0,0 +1,172 @@
+//! Pairs with a remote ContaraNAS instance by decoding a `contara://`
+//! connection URI from a QR code, so headless boxes can be set up by
+//! scanning a printed code instead of typing host/port/token by hand.
+
+use qt_widgets::qt_core::QString;
+use qt_widgets::qt_gui::QImage;
+use qt_widgets::{QFileDialog, QMessageBox, QWidget};
+use std::fmt;
+
+/// Connection details recovered from a scanned pairing QR code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionSettings {
+    pub host: String,
+    pub port: u16,
+    pub token: String,
+}
+
+#[derive(Debug)]
+pub enum PairingError {
+    Cancelled,
+    NoQrCodeFound,
+    Decode(String),
+    InvalidUri(String),
+}
+
+impl fmt::Display for PairingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PairingError::Cancelled => write!(f, "no image was selected"),
+            PairingError::NoQrCodeFound => write!(f, "no QR code found in the image"),
+            PairingError::Decode(msg) => write!(f, "could not decode QR code: {msg}"),
+            PairingError::InvalidUri(uri) => write!(f, "not a valid contara:// pairing code: {uri}"),
+        }
+    }
+}
+
+impl std::error::Error for PairingError {}
+
+/// Prompts for an image file via a file-open dialog and decodes it into
+/// connection settings. Returns [`PairingError::Cancelled`] if the user
+/// closes the dialog without choosing a file.
+///
+/// # Safety
+/// Must be called on the Qt GUI thread.
+pub unsafe fn pick_and_decode(parent: &QWidget) -> Result<ConnectionSettings, PairingError> {
+    let path = QFileDialog::get_open_file_name_4a(
+        parent,
+        &QString::from_std_str("Select Pairing QR Code"),
+        &QString::from_std_str(""),
+        &QString::from_std_str("Images (*.png *.jpg *.jpeg *.bmp)"),
+    );
+    if path.is_empty() {
+        return Err(PairingError::Cancelled);
+    }
+
+    let image = QImage::from_q_string(&path)
+        .convert_to_format_format(qt_widgets::qt_gui::Format::FormatGrayscale8);
+    let width = image.width() as usize;
+    let height = image.height() as usize;
+
+    // `Format_Grayscale8` already stores one true intensity byte per
+    // pixel, so each scanline can be copied directly — no per-pixel
+    // FFI round trip, and no risk of reading an inverted channel like
+    // CMYK "black" would give us.
+    let mut luma = vec![0u8; width * height];
+    for y in 0..height {
+        let row = std::slice::from_raw_parts(image.scan_line(y as i32), width);
+        luma[y * width..(y + 1) * width].copy_from_slice(row);
+    }
+
+    decode_pairing_image(&luma, width, height)
+}
+
+/// Decodes a grayscale image buffer (row-major, one byte per pixel) into
+/// connection settings by locating the QR code's finder patterns,
+/// sampling the module matrix, and parsing the resulting payload as a
+/// `contara://host:port?token=...` URI.
+pub fn decode_pairing_image(
+    luma: &[u8],
+    width: usize,
+    height: usize,
+) -> Result<ConnectionSettings, PairingError> {
+    let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(width, height, |x, y| luma[y * width + x]);
+    let grid = prepared
+        .detect_grids()
+        .into_iter()
+        .next()
+        .ok_or(PairingError::NoQrCodeFound)?;
+    let (_, content) = grid
+        .decode()
+        .map_err(|err| PairingError::Decode(err.to_string()))?;
+    parse_pairing_uri(&content)
+}
+
+/// Parses a `contara://host:port?token=...` pairing URI.
+fn parse_pairing_uri(uri: &str) -> Result<ConnectionSettings, PairingError> {
+    let rest = uri
+        .strip_prefix("contara://")
+        .ok_or_else(|| PairingError::InvalidUri(uri.to_string()))?;
+    let (host_port, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (host, port) = host_port
+        .split_once(':')
+        .ok_or_else(|| PairingError::InvalidUri(uri.to_string()))?;
+    let port: u16 = port
+        .parse()
+        .map_err(|_| PairingError::InvalidUri(uri.to_string()))?;
+    let token = query
+        .strip_prefix("token=")
+        .ok_or_else(|| PairingError::InvalidUri(uri.to_string()))?;
+
+    Ok(ConnectionSettings {
+        host: host.to_string(),
+        port,
+        token: token.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_host_port_and_token() {
+        let settings = parse_pairing_uri("contara://nas.local:4443?token=abc123").unwrap();
+        assert_eq!(settings.host, "nas.local");
+        assert_eq!(settings.port, 4443);
+        assert_eq!(settings.token, "abc123");
+    }
+
+    #[test]
+    fn rejects_missing_scheme() {
+        let err = parse_pairing_uri("nas.local:4443?token=abc123").unwrap_err();
+        assert!(matches!(err, PairingError::InvalidUri(_)));
+    }
+
+    #[test]
+    fn rejects_missing_port() {
+        let err = parse_pairing_uri("contara://nas.local?token=abc123").unwrap_err();
+        assert!(matches!(err, PairingError::InvalidUri(_)));
+    }
+
+    #[test]
+    fn rejects_non_numeric_port() {
+        let err = parse_pairing_uri("contara://nas.local:notaport?token=abc123").unwrap_err();
+        assert!(matches!(err, PairingError::InvalidUri(_)));
+    }
+
+    #[test]
+    fn rejects_missing_token() {
+        let err = parse_pairing_uri("contara://nas.local:4443").unwrap_err();
+        assert!(matches!(err, PairingError::InvalidUri(_)));
+    }
+
+    #[test]
+    fn decode_pairing_image_reports_no_qr_code_found_on_a_blank_image() {
+        let blank = vec![255u8; 64 * 64];
+        let err = decode_pairing_image(&blank, 64, 64).unwrap_err();
+        assert!(matches!(err, PairingError::NoQrCodeFound));
+    }
+}
+
+/// Shows a blocking error dialog when pairing fails.
+///
+/// # Safety
+/// Must be called on the Qt GUI thread.
+pub unsafe fn show_pairing_error(parent: &QWidget, err: &PairingError) {
+    QMessageBox::critical_q_widget2_q_string(
+        parent,
+        &QString::from_std_str("Pairing Failed"),
+        &QString::from_std_str(&err.to_string()),
+    );
+}
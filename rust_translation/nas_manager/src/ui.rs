@@ -0,0 +1,8 @@
+//! Runtime support for the Designer-generated widget tree.
+//!
+//! The actual per-window structs and their `load()` constructors are
+//! generated at build time by `build.rs` from the `.ui` files under
+//! `ui/`; editing a `.ui` file and rebuilding is enough to pick up the
+//! change, no hand-written widget code required.
+
+include!(concat!(env!("OUT_DIR"), "/ui.rs"));